@@ -13,13 +13,14 @@
 #![allow(non_camel_case_types)]
 
 use core::mem::ManuallyDrop;
+use core::time::Duration;
 
 use crate::api::ServerUnion;
 use crate::IOX2_OK;
 
 use super::{
-    c_size_t, iox2_allocation_strategy_e, iox2_server_h, iox2_server_t, iox2_service_type_e,
-    iox2_unable_to_deliver_strategy_e, IntoCInt, PayloadFfi, UserHeaderFfi,
+    c_size_t, iox2_allocation_strategy_e, iox2_server_h, iox2_server_h_ref, iox2_server_t,
+    iox2_service_type_e, iox2_unable_to_deliver_strategy_e, IntoCInt, PayloadFfi, UserHeaderFfi,
 };
 use super::{AssertNonNullHandle, HandleToType};
 use core::ffi::{c_char, c_int};
@@ -35,6 +36,7 @@ use iceoryx2_ffi_macros::{iceoryx2_ffi, CStrRepr};
 pub enum iox2_server_create_error_e {
     EXCEEDS_MAX_SUPPORTED_SERVERS = IOX2_OK as isize + 1,
     UNABLE_TO_CREATE_DATA_SEGMENT,
+    UNABLE_TO_CREATE_NOTIFIER,
 }
 
 impl IntoCInt for ServerCreateError {
@@ -341,6 +343,56 @@ pub unsafe extern "C" fn iox2_port_factory_server_builder_unable_to_deliver_stra
     }
 }
 
+/// Sets the timeout for the unable to deliver strategy for the server. The server will
+/// block on a full response queue up to the provided timeout before falling back to the
+/// configured [`iox2_unable_to_deliver_strategy_e`].
+///
+/// # Limitations
+///
+/// This setter only bounds how long a delivery attempt may block; it does not give
+/// responders a way to tell a timeout-triggered fallback apart from an ordinary
+/// discard/block outcome. Doing that requires a distinct variant on the response send
+/// error returned from the core `iceoryx2` crate's send path, which lives outside this FFI
+/// crate and is not part of this function. Callers that need to detect a stalled delivery
+/// cannot rely on this setter alone; treat it as bounding worst-case blocking time only,
+/// not as an error-reporting mechanism.
+///
+/// # Arguments
+///
+/// * `port_factory_handle` - Must be a valid [`iox2_port_factory_server_builder_h_ref`]
+///   obtained by [`iox2_port_factory_request_response_server_builder`](crate::iox2_port_factory_request_response_server_builder).
+/// * `timeout_nanos` - The timeout in nanoseconds
+///
+/// # Safety
+///
+/// * `port_factory_handle` must be valid handles
+#[no_mangle]
+pub unsafe extern "C" fn iox2_port_factory_server_builder_set_deliver_timeout(
+    port_factory_handle: iox2_port_factory_server_builder_h_ref,
+    timeout_nanos: u64,
+) {
+    port_factory_handle.assert_non_null();
+
+    let handle = unsafe { &mut *port_factory_handle.as_type() };
+    let timeout = Duration::from_nanos(timeout_nanos);
+    match handle.service_type {
+        iox2_service_type_e::IPC => {
+            let builder = ManuallyDrop::take(&mut handle.value.as_mut().ipc);
+
+            handle.set(PortFactoryServerBuilderUnion::new_ipc(
+                builder.unable_to_deliver_strategy_timeout(timeout),
+            ));
+        }
+        iox2_service_type_e::LOCAL => {
+            let builder = ManuallyDrop::take(&mut handle.value.as_mut().local);
+
+            handle.set(PortFactoryServerBuilderUnion::new_local(
+                builder.unable_to_deliver_strategy_timeout(timeout),
+            ));
+        }
+    }
+}
+
 /// Creates a server and consumes the builder
 ///
 /// # Arguments
@@ -402,9 +454,14 @@ pub unsafe extern "C" fn iox2_port_factory_server_builder_create(
             let builder = ManuallyDrop::into_inner(builder.local);
 
             match builder.create() {
-                Ok(publisher) => {
-                    (*struct_ptr).init(service_type, ServerUnion::new_local(publisher), deleter);
-                }
+                Ok(publisher) => match ServerUnion::new_local(publisher) {
+                    Ok(server) => {
+                        (*struct_ptr).init(service_type, server, deleter);
+                    }
+                    Err(_) => {
+                        return iox2_server_create_error_e::UNABLE_TO_CREATE_NOTIFIER as c_int;
+                    }
+                },
                 Err(error) => {
                     return error.into_c_int();
                 }
@@ -417,4 +474,39 @@ pub unsafe extern "C" fn iox2_port_factory_server_builder_create(
     IOX2_OK
 }
 
+/// Returns the file descriptor of the server that becomes ready to read whenever a new
+/// request is available.
+///
+/// The returned file descriptor follows level-triggered semantics, i.e. it stays readable
+/// as long as at least one undelivered request is queued on the server. The caller must
+/// drain the server, by receiving requests until none are left, before the file descriptor
+/// clears, the same way it is expected from an `eventfd`/`epoll` based consumer. This makes
+/// it possible to wait on many servers, and on other foreign file descriptors, in a single
+/// `epoll_wait`/`select`/`poll` call instead of relying on iceoryx2's own
+/// [`WaitSet`](iceoryx2::waitset::WaitSet).
+///
+/// # Arguments
+///
+/// * `server_handle` - Must be a valid [`iox2_server_h_ref`] obtained by
+///   [`iox2_port_factory_server_builder_create`].
+///
+/// # Returns
+///
+/// The underlying, borrowed file descriptor. It is owned by the server and must not be
+/// closed by the caller.
+///
+/// # Safety
+///
+/// * `server_handle` must be valid
+#[no_mangle]
+pub unsafe extern "C" fn iox2_server_get_file_descriptor(
+    server_handle: iox2_server_h_ref,
+) -> c_int {
+    server_handle.assert_non_null();
+
+    let server = unsafe { &mut *server_handle.as_type() };
+    let service_type = server.service_type;
+    unsafe { server.value.as_mut().notifier_fd(service_type) }
+}
+
 // END C API
@@ -0,0 +1,196 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![allow(non_camel_case_types)]
+
+use core::mem::ManuallyDrop;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use super::{iox2_service_type_e, PayloadFfi, UserHeaderFfi};
+use core::ffi::c_int;
+use iceoryx2::port::server::Server;
+use iceoryx2::prelude::*;
+use iceoryx2::waitset::{CallbackProgression, WaitSetBuilder};
+use iceoryx2_bb_posix::event_fd::{EventFd, EventFdBuilder};
+use iceoryx2_bb_posix::file_descriptor::FileDescriptorBased;
+
+/// The eventfd could not be created, e.g. because the process is already at its file
+/// descriptor table limit.
+#[derive(Debug)]
+pub(super) struct NotifierCreationError;
+
+pub(super) struct IpcServer {
+    pub(super) server: Server<ipc::Service, PayloadFfi, UserHeaderFfi, PayloadFfi, UserHeaderFfi>,
+    pub(super) notifier_fd: c_int,
+}
+
+/// `local::Service` servers have no OS-backed wakeup source of their own, unlike
+/// `ipc::Service` servers which are already built on one (the same one the `WaitSet`
+/// listens on). To give C callers a pollable fd regardless, a dedicated `eventfd` is
+/// created and a background thread forwards the server's own internal request
+/// notification, obtained through a `WaitSet` attachment, onto that `eventfd`. The thread
+/// is joined on drop, which is why this struct, unlike [`IpcServer`], needs an explicit
+/// `Drop` impl.
+pub(super) struct LocalServer {
+    pub(super) server:
+        Arc<Server<local::Service, PayloadFfi, UserHeaderFfi, PayloadFfi, UserHeaderFfi>>,
+    pub(super) notifier: Arc<EventFd>,
+    keep_forwarding: Arc<AtomicBool>,
+    forwarder: Option<thread::JoinHandle<()>>,
+}
+
+impl LocalServer {
+    fn new(
+        server: Server<local::Service, PayloadFfi, UserHeaderFfi, PayloadFfi, UserHeaderFfi>,
+        notifier: EventFd,
+    ) -> Self {
+        let server = Arc::new(server);
+        let notifier = Arc::new(notifier);
+        let keep_forwarding = Arc::new(AtomicBool::new(true));
+
+        let forwarder = {
+            let server = Arc::clone(&server);
+            let notifier = Arc::clone(&notifier);
+            let keep_forwarding = Arc::clone(&keep_forwarding);
+            thread::spawn(move || {
+                let Ok(waitset) = WaitSetBuilder::new().create::<local::Service>() else {
+                    return;
+                };
+                let Ok(_guard) = waitset.attach_notification(&*server) else {
+                    return;
+                };
+                while keep_forwarding.load(Ordering::Relaxed) {
+                    // Wakes up periodically even without a request so `keep_forwarding`
+                    // is re-checked and the thread can be joined from `Drop`.
+                    let _ = waitset.wait_and_process_once_with_timeout(
+                        |_attachment_id| {
+                            let _ = notifier.notify(1);
+                            CallbackProgression::Stop
+                        },
+                        Duration::from_millis(50),
+                    );
+                    // Reset the eventfd once the server is drained so it is only readable
+                    // while at least one request is queued.
+                    if let Ok(false) = server.has_requests() {
+                        let _ = notifier.try_wait();
+                    }
+                }
+            })
+        };
+
+        Self {
+            server,
+            notifier,
+            keep_forwarding,
+            forwarder: Some(forwarder),
+        }
+    }
+}
+
+impl Drop for LocalServer {
+    fn drop(&mut self) {
+        self.keep_forwarding.store(false, Ordering::Relaxed);
+        if let Some(forwarder) = self.forwarder.take() {
+            let _ = forwarder.join();
+        }
+    }
+}
+
+pub(super) union ServerUnion {
+    pub(super) ipc: ManuallyDrop<IpcServer>,
+    pub(super) local: ManuallyDrop<LocalServer>,
+}
+
+impl ServerUnion {
+    pub(super) fn new_ipc(
+        server: Server<ipc::Service, PayloadFfi, UserHeaderFfi, PayloadFfi, UserHeaderFfi>,
+    ) -> Self {
+        let notifier_fd = server.file_descriptor().native_handle();
+        Self {
+            ipc: ManuallyDrop::new(IpcServer {
+                server,
+                notifier_fd,
+            }),
+        }
+    }
+
+    pub(super) fn new_local(
+        server: Server<local::Service, PayloadFfi, UserHeaderFfi, PayloadFfi, UserHeaderFfi>,
+    ) -> Result<Self, NotifierCreationError> {
+        let notifier = EventFdBuilder::new()
+            .create()
+            .map_err(|_| NotifierCreationError)?;
+        Ok(Self {
+            local: ManuallyDrop::new(LocalServer::new(server, notifier)),
+        })
+    }
+
+    /// Returns the stored notifier/listener fd for the given service type. For
+    /// `local::Service` this is the dedicated `eventfd` forwarded to in [`LocalServer`],
+    /// not a fd belonging to the server itself.
+    ///
+    /// # Safety
+    ///
+    /// * `self` must be initialized for `service_type`
+    pub(super) unsafe fn notifier_fd(&mut self, service_type: iox2_service_type_e) -> c_int {
+        match service_type {
+            iox2_service_type_e::IPC => self.ipc.notifier_fd,
+            iox2_service_type_e::LOCAL => self.local.notifier.file_descriptor().native_handle(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::time::Duration as StdDuration;
+
+    fn is_readable(fd: c_int) -> bool {
+        let mut pollfd = libc::pollfd {
+            fd,
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        unsafe { libc::poll(&mut pollfd, 1, 200) };
+        pollfd.revents & libc::POLLIN != 0
+    }
+
+    #[test]
+    fn local_server_file_descriptor_becomes_readable_when_request_is_sent() {
+        let service_name = ServiceName::new("iox2_server_fd_tests").unwrap();
+        let node = NodeBuilder::new().create::<local::Service>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let server = service.server_builder().create().unwrap();
+        let client = service.client_builder().create().unwrap();
+
+        let mut server_union = ServerUnion::new_local(server).unwrap();
+        let fd = unsafe { server_union.notifier_fd(iox2_service_type_e::LOCAL) };
+
+        assert!(!is_readable(fd));
+
+        let request = client.loan_uninit().unwrap();
+        let request = request.write_payload(42);
+        request.send().unwrap();
+
+        // give the forwarder thread time to observe the request and signal the eventfd
+        std::thread::sleep(StdDuration::from_millis(100));
+        assert!(is_readable(fd));
+    }
+}